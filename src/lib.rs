@@ -16,6 +16,8 @@ type Constructor = Arc<dyn for<'r> Fn(&'r Resolver) -> Box<dyn Any> + Send + Syn
 pub struct Container<P: GetConstructor = ()> {
     parent: P,
     constructors: BTreeMap<TypeId, Constructor>,
+    named_constructors: BTreeMap<(TypeId, String), Constructor>,
+    many_constructors: BTreeMap<TypeId, Vec<Constructor>>,
 }
 
 impl Container {
@@ -24,6 +26,8 @@ impl Container {
         Self {
             parent: (),
             constructors: Default::default(),
+            named_constructors: Default::default(),
+            many_constructors: Default::default(),
         }
     }
 
@@ -32,6 +36,8 @@ impl Container {
         Container {
             parent,
             constructors: Default::default(),
+            named_constructors: Default::default(),
+            many_constructors: Default::default(),
         }
     }
 }
@@ -39,6 +45,8 @@ impl Container {
 #[derive(Debug)]
 pub enum Error {
     AlreadyRegistered,
+    /// There was no existing constructor to decorate with [`Container::when_decorate`].
+    NotRegistered,
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -46,6 +54,34 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub struct Registration<'container, R, P: GetConstructor> {
     _phantom: PhantomData<R>,
     container: &'container mut Container<P>,
+    /// When `true`, registering replaces an existing constructor instead of erroring.
+    replace: bool,
+    /// When set, registers under this name instead of the plain `TypeId` binding.
+    name: Option<String>,
+    /// When `true`, registering appends to the `TypeId`'s bindings instead of erroring.
+    many: bool,
+}
+
+impl<'container, R, P> Registration<'container, R, P>
+where
+    R: 'static,
+    P: GetConstructor,
+{
+    fn register(self, constructor: Constructor) -> Result<()> {
+        if let Some(name) = self.name {
+            return self.container.register_named_constructor::<R>(name, constructor);
+        }
+        if self.many {
+            self.container.register_many_constructor::<R>(constructor);
+            return Ok(());
+        }
+        if self.replace {
+            self.container.register_constructor_override::<R>(constructor);
+            Ok(())
+        } else {
+            self.container.register_constructor::<R>(constructor)
+        }
+    }
 }
 
 impl<'container, R, P> Registration<'container, R, P>
@@ -56,7 +92,7 @@ where
     pub fn clone(self, value: R) -> Result<()> {
         let value = Box::new(value);
         let constructor = Arc::new(move |_: &Resolver| value.clone() as Box<dyn Any>);
-        self.container.register_constructor::<R>(constructor)
+        self.register(constructor)
     }
 }
 
@@ -68,7 +104,7 @@ where
     pub fn construct_it(self) -> Result<()> {
         let constructor =
             Arc::new(move |locator: &Resolver| Box::new(R::construct(locator)) as Box<dyn Any>);
-        self.container.register_constructor::<R>(constructor)
+        self.register(constructor)
     }
 }
 
@@ -85,7 +121,7 @@ where
             let new = Box::new(E::construct_as(locator));
             new as Box<dyn Any>
         });
-        self.container.register_constructor::<R>(constructor)
+        self.register(constructor)
     }
 }
 
@@ -100,19 +136,249 @@ where
     {
         let constructor =
             Arc::new(move |resolver: &Resolver| Box::new((constructor)(resolver)) as Box<dyn Any>);
-        self.container.register_constructor::<R>(constructor)
+        self.register(constructor)
+    }
+}
+
+impl<'container, R, P> Registration<'container, Arc<R>, P>
+where
+    R: 'static + ?Sized,
+    P: GetConstructor,
+{
+    /// Returns a builder for a dependency that is constructed once and shared for the
+    /// lifetime of the `Container`.
+    pub fn singleton(self) -> Singleton<'container, Arc<R>, P> {
+        Singleton {
+            container: self.container,
+            phantom: self._phantom,
+        }
+    }
+
+    /// Returns a builder for a dependency that is constructed once per [`Scope`] and
+    /// shared for the lifetime of that scope.
+    pub fn scoped(self) -> Scoped<'container, Arc<R>, P> {
+        Scoped {
+            container: self.container,
+            phantom: self._phantom,
+        }
+    }
+}
+
+pub struct Singleton<'container, R, P>
+where
+    P: GetConstructor,
+{
+    container: &'container mut Container<P>,
+    phantom: PhantomData<R>,
+}
+
+impl<'container, R, P> Singleton<'container, Arc<R>, P>
+where
+    R: 'static + Construct + Send + Sync,
+    P: GetConstructor,
+{
+    pub fn construct_it(self) -> Result<()> {
+        self.construct_with(|locator| Arc::new(R::construct(locator)))
+    }
+}
+
+impl<'container, R, P> Singleton<'container, Arc<R>, P>
+where
+    R: 'static + Send + Sync + ?Sized,
+    P: GetConstructor,
+{
+    pub fn construct<E>(self) -> Result<()>
+    where
+        E: 'static + ConstructAs<Target = Arc<R>> + Send + Sync,
+    {
+        self.construct_with(|locator| E::construct_as(locator))
+    }
+}
+
+impl<'container, R, P> Singleton<'container, Arc<R>, P>
+where
+    R: 'static + Send + Sync + ?Sized,
+    P: GetConstructor,
+{
+    pub fn construct_with<F>(self, constructor: F) -> Result<()>
+    where
+        F: Fn(&Resolver) -> Arc<R> + Send + Sync + 'static,
+    {
+        let singleton: Mutex<Option<Arc<R>>> = Mutex::new(None);
+        let constructor = Arc::new(move |locator: &Resolver| {
+            if let Some(arc) = &*singleton.lock().unwrap() {
+                return Box::new(arc.clone()) as Box<dyn Any>;
+            }
+            let value = constructor(locator);
+            *singleton.lock().unwrap() = Some(value.clone());
+            Box::new(value) as Box<dyn Any>
+        });
+        self.container.register_constructor::<Arc<R>>(constructor)
+    }
+}
+
+/// Cache of the scoped dependencies constructed so far within one [`Scope`].
+type ScopeCache = Mutex<BTreeMap<TypeId, Box<dyn Any + Send + Sync>>>;
+
+/// A per-scope lifetime, e.g. one request or one frame, created via
+/// [`Container::enter_scope`]. Dependencies registered with
+/// [`scoped`](Registration::scoped) are constructed once per `Scope` and shared for
+/// the lifetime of that scope; a different `Scope` gets its own instance.
+pub struct Scope<'container, P: GetConstructor> {
+    container: &'container Container<P>,
+    cache: Arc<ScopeCache>,
+}
+
+impl<P: GetConstructor> Scope<'_, P> {
+    /// Get a `Resolver` that resolves scoped dependencies against this `Scope`.
+    pub fn as_resolver(&self) -> Resolver<'_> {
+        Resolver {
+            locator: self.container,
+            path: Rc::new(RefCell::new(Vec::new())),
+            scope: Some(self.cache.clone()),
+        }
+    }
+}
+
+pub struct Scoped<'container, R, P>
+where
+    P: GetConstructor,
+{
+    container: &'container mut Container<P>,
+    phantom: PhantomData<R>,
+}
+
+impl<'container, R, P> Scoped<'container, Arc<R>, P>
+where
+    R: 'static + Construct + Send + Sync,
+    P: GetConstructor,
+{
+    pub fn construct_it(self) -> Result<()> {
+        self.construct_with(|locator| Arc::new(R::construct(locator)))
+    }
+}
+
+impl<'container, R, P> Scoped<'container, Arc<R>, P>
+where
+    R: 'static + Send + Sync + ?Sized,
+    P: GetConstructor,
+{
+    pub fn construct<E>(self) -> Result<()>
+    where
+        E: 'static + ConstructAs<Target = Arc<R>> + Send + Sync,
+    {
+        self.construct_with(|locator| E::construct_as(locator))
+    }
+}
+
+impl<'container, R, P> Scoped<'container, Arc<R>, P>
+where
+    R: 'static + Send + Sync + ?Sized,
+    P: GetConstructor,
+{
+    pub fn construct_with<F>(self, constructor: F) -> Result<()>
+    where
+        F: Fn(&Resolver) -> Arc<R> + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<Arc<R>>();
+        let constructor = Arc::new(move |resolver: &Resolver| {
+            let Some(scope) = &resolver.scope else {
+                return Box::new(constructor(resolver)) as Box<dyn Any>;
+            };
+            if let Some(cached) = scope.lock().unwrap().get(&type_id) {
+                let arc = cached
+                    .downcast_ref::<Arc<R>>()
+                    .expect("cached scoped value has the wrong type");
+                return Box::new(arc.clone()) as Box<dyn Any>;
+            }
+            let value = constructor(resolver);
+            scope
+                .lock()
+                .unwrap()
+                .insert(type_id, Box::new(value.clone()));
+            Box::new(value) as Box<dyn Any>
+        });
+        self.container.register_constructor::<Arc<R>>(constructor)
     }
 }
 
 impl<P: GetConstructor> Container<P> {
     #[must_use]
-    pub fn when<R>(&mut self) -> Registration<R, P> {
+    pub fn when<R>(&mut self) -> Registration<'_, R, P> {
         Registration {
             _phantom: PhantomData,
             container: self,
+            replace: false,
+            name: None,
+            many: false,
         }
     }
 
+    /// Like [`when`](Self::when), but replaces an existing registration for `R` instead of
+    /// erroring, e.g. to swap in a test double for an already-configured service.
+    #[must_use]
+    pub fn when_override<R>(&mut self) -> Registration<'_, R, P> {
+        Registration {
+            _phantom: PhantomData,
+            container: self,
+            replace: true,
+            name: None,
+            many: false,
+        }
+    }
+
+    /// Like [`when`](Self::when), but registers `R` under `name` so several bindings of
+    /// the same type can coexist, e.g. a "music" and a "sfx" `Arc<dyn AudioManager>`.
+    /// Resolve it with [`Resolver::resolve_named`].
+    #[must_use]
+    pub fn when_named<R>(&mut self, name: impl Into<String>) -> Registration<'_, R, P> {
+        Registration {
+            _phantom: PhantomData,
+            container: self,
+            replace: false,
+            name: Some(name.into()),
+            many: false,
+        }
+    }
+
+    /// Like [`when`](Self::when), but appends `R` to the set of bindings for that type
+    /// instead of erroring on a duplicate, e.g. to register several plugins of one
+    /// trait. Resolve them all with [`Resolver::resolve_all`].
+    #[must_use]
+    pub fn when_many<R>(&mut self) -> Registration<'_, R, P> {
+        Registration {
+            _phantom: PhantomData,
+            container: self,
+            replace: false,
+            name: None,
+            many: true,
+        }
+    }
+
+    /// Wrap the output of the constructor already registered for `R` with `decorate`,
+    /// e.g. to add instrumentation around an existing service or to wrap it with a
+    /// mock/null backend in tests.
+    pub fn when_decorate<R, F>(&mut self, decorate: F) -> Result<()>
+    where
+        R: 'static,
+        F: Fn(R, &Resolver) -> R + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<R>();
+        let inner = self
+            .constructors
+            .get(&type_id)
+            .cloned()
+            .ok_or(Error::NotRegistered)?;
+        let constructor = Arc::new(move |resolver: &Resolver| {
+            let value = *(inner)(resolver)
+                .downcast::<R>()
+                .expect("decorated constructor produced an unexpected type");
+            Box::new(decorate(value, resolver)) as Box<dyn Any>
+        });
+        self.register_constructor_override::<R>(constructor);
+        Ok(())
+    }
+
     fn register_constructor<T: 'static>(&mut self, constructor: Constructor) -> Result<()> {
         match self.constructors.insert(TypeId::of::<T>(), constructor) {
             Some(_) => Err(Error::AlreadyRegistered),
@@ -120,6 +386,31 @@ impl<P: GetConstructor> Container<P> {
         }
     }
 
+    fn register_constructor_override<T: 'static>(&mut self, constructor: Constructor) {
+        self.constructors.insert(TypeId::of::<T>(), constructor);
+    }
+
+    fn register_named_constructor<T: 'static>(
+        &mut self,
+        name: String,
+        constructor: Constructor,
+    ) -> Result<()> {
+        match self
+            .named_constructors
+            .insert((TypeId::of::<T>(), name), constructor)
+        {
+            Some(_) => Err(Error::AlreadyRegistered),
+            None => Ok(()),
+        }
+    }
+
+    fn register_many_constructor<T: 'static>(&mut self, constructor: Constructor) {
+        self.many_constructors
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(constructor);
+    }
+
     // Register the type `T` to be constructed when it is needed and an `Rc` is given out upon calling `resolve`.
     pub fn register_singleton<T: Construct + Send + Sync + 'static>(&mut self) -> Result<()> {
         let singleton: Mutex<Option<Arc<T>>> = Mutex::new(None);
@@ -135,30 +426,151 @@ impl<P: GetConstructor> Container<P> {
     }
 
     /// Get a `Resolver` that borrows the `Container`
-    pub fn as_resolver(&self) -> Resolver {
-        Resolver(self)
+    pub fn as_resolver(&self) -> Resolver<'_> {
+        Resolver {
+            locator: self,
+            path: Rc::new(RefCell::new(Vec::new())),
+            scope: None,
+        }
+    }
+
+    /// Start a new [`Scope`] borrowing this `Container`. Dependencies registered with
+    /// [`scoped`](Registration::scoped) are constructed once per `Scope` and shared for
+    /// its lifetime; resolving through a different `Scope` yields a different instance.
+    pub fn enter_scope(&self) -> Scope<'_, P> {
+        Scope {
+            container: self,
+            cache: Arc::new(Mutex::new(BTreeMap::new())),
+        }
     }
 }
 
-pub struct Resolver<'r>(&'r dyn GetConstructor);
+#[derive(Debug)]
+pub enum ResolveError {
+    /// A constructor further up the resolution path requested a type that is
+    /// already being constructed, captured as the resolution path at that point: the
+    /// `TypeId`s being constructed, outermost first. The repeated type is not appended
+    /// again at the end.
+    Cycle(Vec<TypeId>),
+    /// No constructor was registered for the requested type.
+    NotRegistered(TypeId),
+    /// The constructor for `type_id` failed, wrapping the error from the dependency
+    /// that caused it so the chain names each type along the failed path.
+    Construction {
+        type_id: TypeId,
+        source: Box<ResolveError>,
+    },
+}
+
+/// The error a [`TryConstruct`] implementation reports when it fails to build `Self`.
+pub type ConstructError = ResolveError;
+
+pub struct Resolver<'r> {
+    locator: &'r dyn GetConstructor,
+    path: Rc<RefCell<Vec<TypeId>>>,
+    scope: Option<Arc<ScopeCache>>,
+}
+
+/// Pops the most recently pushed `TypeId` off the resolution path, including on unwind.
+struct PathGuard<'a>(&'a RefCell<Vec<TypeId>>);
+
+impl Drop for PathGuard<'_> {
+    fn drop(&mut self) {
+        self.0.borrow_mut().pop();
+    }
+}
 
 impl Resolver<'_> {
     pub fn resolve<T: 'static>(&self) -> Option<T> {
-        self.0
-            .get_constructor(&TypeId::of::<T>())
+        let type_id = TypeId::of::<T>();
+        if self.path.borrow().contains(&type_id) {
+            panic!("{:?}", ResolveError::Cycle(self.path.borrow().clone()));
+        }
+        self.path.borrow_mut().push(type_id);
+        let _guard = PathGuard(&self.path);
+        self.locator
+            .get_constructor(&type_id)
             .and_then(|constructor| (constructor)(self).downcast::<T>().ok())
             .map(|value| *value)
     }
+
+    /// Resolve the binding registered under `name` via [`Container::when_named`].
+    pub fn resolve_named<T: 'static>(&self, name: &str) -> Option<T> {
+        let type_id = TypeId::of::<T>();
+        if self.path.borrow().contains(&type_id) {
+            panic!("{:?}", ResolveError::Cycle(self.path.borrow().clone()));
+        }
+        self.path.borrow_mut().push(type_id);
+        let _guard = PathGuard(&self.path);
+        self.locator
+            .get_named_constructor(&type_id, name)
+            .and_then(|constructor| (constructor)(self).downcast::<T>().ok())
+            .map(|value| *value)
+    }
+
+    /// Resolve every binding registered for `T` via [`Container::when_many`], local
+    /// bindings first followed by the parent `Container`'s, in registration order.
+    pub fn resolve_all<T: 'static>(&self) -> Vec<T> {
+        let type_id = TypeId::of::<T>();
+        if self.path.borrow().contains(&type_id) {
+            panic!("{:?}", ResolveError::Cycle(self.path.borrow().clone()));
+        }
+        self.path.borrow_mut().push(type_id);
+        let _guard = PathGuard(&self.path);
+        self.locator
+            .get_many_constructors(&type_id)
+            .into_iter()
+            .filter_map(|constructor| (constructor)(self).downcast::<T>().ok())
+            .map(|value| *value)
+            .collect()
+    }
+
+    /// Resolve `T`, distinguishing a missing registration from a dependency that failed
+    /// further down the resolution path. Works for any registration; one made with
+    /// [`try_construct_it`](Registration::try_construct_it) can additionally fail.
+    pub fn try_resolve<T: 'static>(&self) -> std::result::Result<T, ResolveError> {
+        let type_id = TypeId::of::<T>();
+        if self.path.borrow().contains(&type_id) {
+            return Err(ResolveError::Cycle(self.path.borrow().clone()));
+        }
+        self.path.borrow_mut().push(type_id);
+        let _guard = PathGuard(&self.path);
+        let constructor = self
+            .locator
+            .get_constructor(&type_id)
+            .ok_or(ResolveError::NotRegistered(type_id))?;
+        let boxed = (constructor)(self);
+        let result = match boxed.downcast::<std::result::Result<T, ConstructError>>() {
+            Ok(result) => *result,
+            Err(boxed) => Ok(*boxed
+                .downcast::<T>()
+                .expect("try_resolve constructor produced an unexpected type")),
+        };
+        result.map_err(|source| ResolveError::Construction {
+            type_id,
+            source: Box::new(source),
+        })
+    }
 }
 
 pub trait GetConstructor {
     fn get_constructor(&self, type_id: &TypeId) -> Option<Constructor>;
+    fn get_named_constructor(&self, type_id: &TypeId, name: &str) -> Option<Constructor>;
+    fn get_many_constructors(&self, type_id: &TypeId) -> Vec<Constructor>;
 }
 
 impl GetConstructor for () {
     fn get_constructor(&self, _type_id: &TypeId) -> Option<Constructor> {
         None
     }
+
+    fn get_named_constructor(&self, _type_id: &TypeId, _name: &str) -> Option<Constructor> {
+        None
+    }
+
+    fn get_many_constructors(&self, _type_id: &TypeId) -> Vec<Constructor> {
+        Vec::new()
+    }
 }
 
 impl<P: GetConstructor> GetConstructor for Container<P> {
@@ -168,6 +580,23 @@ impl<P: GetConstructor> GetConstructor for Container<P> {
             .cloned()
             .or(self.parent.get_constructor(type_id))
     }
+
+    fn get_named_constructor(&self, type_id: &TypeId, name: &str) -> Option<Constructor> {
+        self.named_constructors
+            .get(&(*type_id, name.to_string()))
+            .cloned()
+            .or(self.parent.get_named_constructor(type_id, name))
+    }
+
+    fn get_many_constructors(&self, type_id: &TypeId) -> Vec<Constructor> {
+        let mut constructors = self
+            .many_constructors
+            .get(type_id)
+            .cloned()
+            .unwrap_or_default();
+        constructors.extend(self.parent.get_many_constructors(type_id));
+        constructors
+    }
 }
 
 impl<P: GetConstructor> GetConstructor for &Container<P> {
@@ -177,12 +606,37 @@ impl<P: GetConstructor> GetConstructor for &Container<P> {
             .cloned()
             .or(self.parent.get_constructor(type_id))
     }
+
+    fn get_named_constructor(&self, type_id: &TypeId, name: &str) -> Option<Constructor> {
+        self.named_constructors
+            .get(&(*type_id, name.to_string()))
+            .cloned()
+            .or(self.parent.get_named_constructor(type_id, name))
+    }
+
+    fn get_many_constructors(&self, type_id: &TypeId) -> Vec<Constructor> {
+        let mut constructors = self
+            .many_constructors
+            .get(type_id)
+            .cloned()
+            .unwrap_or_default();
+        constructors.extend(self.parent.get_many_constructors(type_id));
+        constructors
+    }
 }
 
 impl<G: GetConstructor> GetConstructor for Arc<G> {
     fn get_constructor(&self, type_id: &TypeId) -> Option<Constructor> {
         self.deref().get_constructor(type_id)
     }
+
+    fn get_named_constructor(&self, type_id: &TypeId, name: &str) -> Option<Constructor> {
+        self.deref().get_named_constructor(type_id, name)
+    }
+
+    fn get_many_constructors(&self, type_id: &TypeId) -> Vec<Constructor> {
+        self.deref().get_many_constructors(type_id)
+    }
 }
 
 /// Used to create a value of type `Self` from the `ServiceLocator`.
@@ -194,6 +648,25 @@ pub trait ConstructAs: Construct {
     fn construct_as(locator: &Resolver) -> Self::Target;
 }
 
+/// Like [`Construct`], but for dependencies that can fail to build, e.g. because one of
+/// their own dependencies is missing. Resolved with [`Resolver::try_resolve`].
+pub trait TryConstruct: Sized {
+    fn try_construct(locator: &Resolver) -> std::result::Result<Self, ConstructError>;
+}
+
+impl<'container, R, P> Registration<'container, R, P>
+where
+    R: 'static + TryConstruct,
+    P: GetConstructor,
+{
+    pub fn try_construct_it(self) -> Result<()> {
+        let constructor = Arc::new(move |locator: &Resolver| {
+            Box::new(R::try_construct(locator)) as Box<dyn Any>
+        });
+        self.register(constructor)
+    }
+}
+
 macro_rules! impl_delegate_construct {
     ($($type:ty),*) => {
         $(
@@ -1,4 +1,5 @@
 use crate::*;
+use std::any::TypeId;
 
 trait AudioManager: Send + Sync {
     fn play(&self);
@@ -260,6 +261,159 @@ fn mutex() {
     boss.lock().unwrap().fire();
 }
 
+struct CycleA;
+impl Construct for CycleA {
+    fn construct(locator: &Resolver) -> Self {
+        let _: CycleB = locator.resolve().unwrap();
+        Self
+    }
+}
+
+struct CycleB;
+impl Construct for CycleB {
+    fn construct(locator: &Resolver) -> Self {
+        let _: CycleA = locator.resolve().unwrap();
+        Self
+    }
+}
+
+#[test]
+#[should_panic]
+fn cycle_detection() {
+    let mut locator = Container::new();
+    locator.when::<CycleA>().construct_it().unwrap();
+    locator.when::<CycleB>().construct_it().unwrap();
+
+    let resolver = locator.as_resolver();
+    let _a: CycleA = resolver.resolve().unwrap();
+}
+
+#[test]
+fn scoped_shares_instance_within_a_scope() {
+    let mut locator = Container::new();
+    locator
+        .when::<Arc<Boss>>()
+        .scoped()
+        .construct_with(|_locator| {
+            Arc::new(Boss {
+                logger: Arc::new(Logger),
+            })
+        })
+        .unwrap();
+
+    let scope = locator.enter_scope();
+    let resolver = scope.as_resolver();
+
+    let first: Arc<Boss> = resolver.resolve().unwrap();
+    let second: Arc<Boss> = resolver.resolve().unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+}
+
+#[test]
+fn when_override_swaps_registration() {
+    let mut container = Container::new();
+    container
+        .when::<Arc<dyn AudioManager>>()
+        .construct_with(|_locator| Arc::new(ProductionAudioManager) as Arc<dyn AudioManager>)
+        .unwrap();
+    container
+        .when_override::<Arc<dyn AudioManager>>()
+        .construct::<Arc<TestAudioManager>>()
+        .unwrap();
+
+    let audio_manager: Arc<dyn AudioManager> = container.as_resolver().resolve().unwrap();
+    audio_manager.play();
+}
+
+#[test]
+fn when_decorate_wraps_existing_registration() {
+    let mut container = Container::new();
+    container.when::<u32>().clone(42).unwrap();
+    container
+        .when_decorate::<u32, _>(|inner, _locator| inner + 1)
+        .unwrap();
+
+    let value: u32 = container.as_resolver().resolve().unwrap();
+    assert_eq!(value, 43);
+}
+
+#[test]
+fn when_named_keeps_multiple_bindings_of_one_type() {
+    let mut container = Container::new();
+    container
+        .when_named::<Arc<dyn AudioManager>>("music")
+        .construct::<Arc<TestAudioManager>>()
+        .unwrap();
+    container
+        .when_named::<Arc<dyn AudioManager>>("sfx")
+        .clone(Arc::new(ProductionAudioManager))
+        .unwrap();
+
+    let resolver = container.as_resolver();
+    let music: Arc<dyn AudioManager> = resolver.resolve_named("music").unwrap();
+    let sfx: Arc<dyn AudioManager> = resolver.resolve_named("sfx").unwrap();
+    music.play();
+    sfx.play();
+}
+
+#[test]
+fn when_many_collects_all_bindings() {
+    let mut container = Container::new();
+    container
+        .when_many::<Arc<dyn AudioManager>>()
+        .construct::<Arc<TestAudioManager>>()
+        .unwrap();
+    container
+        .when_many::<Arc<dyn AudioManager>>()
+        .clone(Arc::new(ProductionAudioManager))
+        .unwrap();
+
+    let audio_managers: Vec<Arc<dyn AudioManager>> = container.as_resolver().resolve_all();
+    assert_eq!(audio_managers.len(), 2);
+    for audio_manager in &audio_managers {
+        audio_manager.play();
+    }
+}
+
+#[test]
+fn when_many_includes_parent_bindings() {
+    let mut parent = Container::new();
+    parent
+        .when_many::<Arc<dyn AudioManager>>()
+        .clone(Arc::new(ProductionAudioManager))
+        .unwrap();
+
+    let mut child = Container::with_parent(&parent);
+    child
+        .when_many::<Arc<dyn AudioManager>>()
+        .construct::<Arc<TestAudioManager>>()
+        .unwrap();
+
+    let audio_managers: Vec<Arc<dyn AudioManager>> = child.as_resolver().resolve_all();
+    assert_eq!(audio_managers.len(), 2);
+}
+
+#[test]
+fn scoped_differs_across_scopes() {
+    let mut locator = Container::new();
+    locator
+        .when::<Arc<Boss>>()
+        .scoped()
+        .construct_with(|_locator| {
+            Arc::new(Boss {
+                logger: Arc::new(Logger),
+            })
+        })
+        .unwrap();
+
+    let first_scope = locator.enter_scope();
+    let second_scope = locator.enter_scope();
+
+    let first: Arc<Boss> = first_scope.as_resolver().resolve().unwrap();
+    let second: Arc<Boss> = second_scope.as_resolver().resolve().unwrap();
+    assert!(!Arc::ptr_eq(&first, &second));
+}
+
 #[test]
 fn threads() {
     let mut locator = Container::new();
@@ -274,3 +428,88 @@ fn threads() {
         let _boss: Arc<Mutex<Boss>> = resolver.resolve().unwrap();
     });
 }
+
+#[derive(Debug)]
+struct FallibleValue(u32);
+impl TryConstruct for FallibleValue {
+    fn try_construct(locator: &Resolver) -> std::result::Result<Self, ConstructError> {
+        let value: u32 = locator.try_resolve()?;
+        Ok(Self(value))
+    }
+}
+
+#[test]
+fn try_resolve_reports_not_registered() {
+    let container = Container::new();
+    let resolver = container.as_resolver();
+
+    let error = resolver.try_resolve::<u32>().unwrap_err();
+    assert!(matches!(error, ResolveError::NotRegistered(type_id) if type_id == TypeId::of::<u32>()));
+}
+
+#[test]
+fn try_resolve_wraps_a_failed_dependency() {
+    let mut container = Container::new();
+    container
+        .when::<FallibleValue>()
+        .try_construct_it()
+        .unwrap();
+
+    let resolver = container.as_resolver();
+    let error = resolver.try_resolve::<FallibleValue>().unwrap_err();
+    match error {
+        ResolveError::Construction { type_id, source } => {
+            assert_eq!(type_id, TypeId::of::<FallibleValue>());
+            assert!(matches!(*source, ResolveError::NotRegistered(type_id) if type_id == TypeId::of::<u32>()));
+        }
+        other => panic!("expected a Construction error, got {other:?}"),
+    }
+}
+
+#[test]
+fn try_resolve_succeeds() {
+    let mut container = Container::new();
+    container.when::<u32>().clone(42).unwrap();
+    container
+        .when::<FallibleValue>()
+        .try_construct_it()
+        .unwrap();
+
+    let resolver = container.as_resolver();
+    let value: FallibleValue = resolver.try_resolve().unwrap();
+    assert_eq!(value.0, 42);
+}
+
+#[derive(Debug)]
+struct TryCycleA;
+impl TryConstruct for TryCycleA {
+    fn try_construct(locator: &Resolver) -> std::result::Result<Self, ConstructError> {
+        let _: TryCycleB = locator.try_resolve()?;
+        Ok(Self)
+    }
+}
+
+struct TryCycleB;
+impl TryConstruct for TryCycleB {
+    fn try_construct(locator: &Resolver) -> std::result::Result<Self, ConstructError> {
+        let _: TryCycleA = locator.try_resolve()?;
+        Ok(Self)
+    }
+}
+
+#[test]
+fn try_resolve_reports_cycle_without_panicking() {
+    let mut container = Container::new();
+    container
+        .when::<TryCycleA>()
+        .try_construct_it()
+        .unwrap();
+    container
+        .when::<TryCycleB>()
+        .try_construct_it()
+        .unwrap();
+
+    let resolver = container.as_resolver();
+    let error = resolver.try_resolve::<TryCycleA>().unwrap_err();
+    assert!(matches!(error, ResolveError::Construction { .. } | ResolveError::Cycle(_)));
+}